@@ -5,8 +5,8 @@ use crate::{
     remacs_sys::{
         build_string, encode_string_utf_8, intern_c_string, make_string_from_utf8, make_user_ptr,
         Ffuncall, Fmake_pipe_process, Fplist_get, Fplist_put, Fprocess_plist, Fset_process_plist,
-        Fuser_ptrp, QCcoding, QCfilter, QCname, QCplist, QCtype, Qcall, Qdata, Qnil, Qraw_text,
-        Qreturn, Qstring, Qstringp, Qt, Quser_ptr, Quser_ptrp, USER_PTRP, XUSER_PTR,
+        Fuser_ptrp, QCcoding, QCfilter, QCname, QCplist, QCtype, Qbytes, Qcall, Qdata, Qerror, Qnil,
+        Qraw_text, Qreturn, Qstring, Qstringp, Qt, Quser_ptr, Quser_ptrp, USER_PTRP, XUSER_PTR,
     },
 };
 
@@ -14,10 +14,11 @@ use remacs_macros::{async_stream, lisp_fn};
 use std::thread;
 
 use std::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     ffi::CString,
     fs::File,
     io::{Read, Write},
+    marker::PhantomData,
     os::unix::io::{FromRawFd, IntoRawFd},
 };
 
@@ -50,6 +51,53 @@ fn nullptr() -> usize {
     std::ptr::null() as *const i32 as usize
 }
 
+// 'File::write' and 'File::read' are free to transfer fewer bytes than
+// asked for (a real pipe can deliver an 8-byte pointer in fragments under
+// load) and can be interrupted by a signal (EINTR) without making any
+// progress at all. These helpers loop until the full buffer has been
+// transferred, retrying transparently on EINTR, so callers never have to
+// reason about partial pointer writes/reads desynchronizing the stream.
+fn write_all_retry(f: &mut File, bytes: &[u8]) -> std::io::Result<()> {
+    let mut written = 0;
+    while written < bytes.len() {
+        match f.write(&bytes[written..]) {
+            Ok(0) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => written += n,
+            Err(ref err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+fn read_exact_retry(f: &mut File, buffer: &mut [u8]) -> std::io::Result<()> {
+    let mut read = 0;
+    while read < buffer.len() {
+        match f.read(&mut buffer[read..]) {
+            Ok(0) => {
+                // Distinct from the deliberate 'close_stream()' sentinel
+                // (a full, successfully-read all-zero buffer): this is the
+                // fd closing mid-message, which is a genuine desync/
+                // corruption signal and must not be swallowed the way the
+                // deliberate close is.
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "stream closed before a full message was received",
+                ))
+            }
+            Ok(n) => read += n,
+            Err(ref err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
 fn is_user_ptr(o: LispObject) -> bool {
     unsafe { Fuser_ptrp(o).into() }
 }
@@ -59,6 +107,7 @@ impl LispObject {
         match self {
             Qstring => Some(String::marker()),
             Quser_ptr => Some(UserData::marker()),
+            Qbytes => Some(Bytes::marker()),
             _ => None,
         }
     }
@@ -67,6 +116,7 @@ impl LispObject {
         match option {
             PipeDataOption::STRING => Qstring,
             PipeDataOption::USER_DATA => Quser_ptr,
+            PipeDataOption::BYTES => Qbytes,
         }
     }
 }
@@ -153,9 +203,11 @@ impl Default for UserData {
 // implement the trait 'PipeData'. This enum
 // is a product of Rust's generic system
 // combined with our usage pattern.
+#[derive(Clone, Copy)]
 pub enum PipeDataOption {
     STRING,
     USER_DATA,
+    BYTES,
 }
 
 pub trait PipeData {
@@ -174,6 +226,186 @@ impl PipeData for UserData {
     }
 }
 
+// Arbitrary bytes transferred losslessly: unlike 'String', a 'Bytes'
+// payload is never interpreted as UTF-8 on the way in, and on the way
+// out it is escaped with WTF-8 (see 'wtf8_encode') rather than lossily
+// re-encoded, so embedded NULs and invalid UTF-8 survive the round trip
+// lisp -> rust -> lisp. The lisp string receives the escaped form, in
+// which each invalid byte is a lone surrogate; 'Bytes::from_wtf8' turns
+// it back into the original bytes exactly.
+pub struct Bytes(pub Vec<u8>);
+
+impl PipeData for Bytes {
+    fn marker() -> PipeDataOption {
+        PipeDataOption::BYTES
+    }
+}
+
+impl Bytes {
+    // Reconstruct the original bytes from a lisp string that holds the
+    // WTF-8 escaped form 'make_return_value' produced, undoing
+    // 'wtf8_encode' byte-for-byte.
+    pub fn from_wtf8(escaped: &[u8]) -> Bytes {
+        Bytes(wtf8_decode(escaped))
+    }
+}
+
+// A minimal WTF-8 style escape used so that a non-UTF-8 'Bytes' payload
+// can still be carried inside a valid-UTF-8 lisp string. Each byte that
+// doesn't fit into a valid UTF-8 sequence is smuggled through as a lone
+// low surrogate (U+DC80..U+DCFF), the same trick 'Wtf8' and Python's
+// 'surrogateescape' use; 'wtf8_decode' reverses it byte-for-byte.
+fn wtf8_encode(bytes: &[u8]) -> Vec<u8> {
+    if std::str::from_utf8(bytes).is_ok() {
+        return bytes.to_vec();
+    }
+
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut rest = bytes;
+    while !rest.is_empty() {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                out.extend_from_slice(valid.as_bytes());
+                break;
+            }
+            Err(err) => {
+                let valid_len = err.valid_up_to();
+                out.extend_from_slice(&rest[..valid_len]);
+                let bad_byte = rest[valid_len];
+                // 'bad_byte' is always >= 0x80 (a lone ASCII byte is
+                // always valid UTF-8 on its own), so this lands in
+                // 0xDC80..0xDCFF, the lone-low-surrogate range
+                // 'wtf8_decode' below checks for.
+                let surrogate = 0xDC00u32 + u32::from(bad_byte);
+                out.push(0xE0 | ((surrogate >> 12) as u8));
+                out.push(0x80 | (((surrogate >> 6) & 0x3F) as u8));
+                out.push(0x80 | ((surrogate & 0x3F) as u8));
+                let skip = err.error_len().unwrap_or(1).max(1);
+                rest = &rest[valid_len + skip..];
+            }
+        }
+    }
+    out
+}
+
+fn wtf8_decode(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if i + 3 <= bytes.len() {
+            let (b0, b1, b2) = (bytes[i], bytes[i + 1], bytes[i + 2]);
+            if b0 & 0xF0 == 0xE0 && b1 & 0xC0 == 0x80 && b2 & 0xC0 == 0x80 {
+                let cp = (u32::from(b0 & 0x0F) << 12)
+                    | (u32::from(b1 & 0x3F) << 6)
+                    | u32::from(b2 & 0x3F);
+                if (0xDC80..=0xDCFF).contains(&cp) {
+                    out.push((cp - 0xDC00) as u8);
+                    i += 3;
+                    continue;
+                }
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    out
+}
+
+// 'rust_worker' can legally send a raw pointer over the pipe because the
+// worker thread shares the lisp thread's address space. That stops being
+// true once the worker runs in a forked child: pointers are meaningless
+// across the fork, so 'rust_worker_process' instead moves the payload's
+// own bytes through the stream, length-prefixed. Only types that are
+// fully self-contained (no address-dependent state) can implement this.
+//
+// 'UserData' deliberately does not implement this trait: only the initial
+// 'fork()' gives the child a coherent copy of the parent's heap, so a
+// 'UserData' boxed afterward (the normal case for a long-lived worker)
+// points at memory the child never had mapped. Reconstructing it there
+// would be a dangling-pointer read.
+pub trait SerializablePipeData: PipeData + Sized {
+    fn to_stream_bytes(self) -> Vec<u8>;
+    fn from_stream_bytes(bytes: Vec<u8>) -> Self;
+}
+
+impl SerializablePipeData for String {
+    fn to_stream_bytes(self) -> Vec<u8> {
+        self.into_bytes()
+    }
+
+    fn from_stream_bytes(bytes: Vec<u8>) -> Self {
+        String::from_utf8_lossy(&bytes).into_owned()
+    }
+}
+
+impl SerializablePipeData for Bytes {
+    fn to_stream_bytes(self) -> Vec<u8> {
+        self.0
+    }
+
+    fn from_stream_bytes(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+fn write_frame(f: &mut File, bytes: &[u8]) -> std::io::Result<()> {
+    write_all_retry(f, &(bytes.len() as u64).to_be_bytes())?;
+    write_all_retry(f, bytes)
+}
+
+fn read_frame(f: &mut File) -> std::io::Result<Vec<u8>> {
+    let mut len_buf = [0; 8];
+    read_exact_retry(f, &mut len_buf)?;
+    let mut buffer = vec![0; usize::try_from(u64::from_be_bytes(len_buf)).unwrap()];
+    read_exact_retry(f, &mut buffer)?;
+    Ok(buffer)
+}
+
+// Which wire protocol a given 'EmacsPipe' process speaks. A thread-based
+// worker ('rust_worker', 'Channel') shares the lisp thread's address
+// space, so it can send a boxed value across as a raw pointer. A
+// fork-based worker ('rust_worker_process') cannot: its payload has to be
+// the value's own serialized bytes, length-prefixed. Lisp needs to know
+// which protocol a given process speaks on both the receive (filter) and
+// send ('async_send_message') sides, which is what this distinguishes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Wire {
+    Pointer,
+    Frame,
+}
+
+// Interns (or looks up) a plain symbol by name. Used for the handful of
+// internal markers below that aren't pre-declared lisp symbols the way
+// 'Qstring'/'Qbytes'/etc. are.
+fn intern_str(name: &str) -> LispObject {
+    let cstr = CString::new(name).expect("interning pipe protocol symbol");
+    unsafe { intern_c_string(cstr.as_ptr()) }
+}
+
+// Plist key marking a process as speaking the framed (fork-based) wire
+// protocol rather than the default pointer-based one.
+fn transport_key() -> LispObject {
+    intern_str("async-transport")
+}
+
+fn transport_frame_marker() -> LispObject {
+    intern_str("async-transport-frame")
+}
+
+fn is_framed_process(plist: LispObject) -> bool {
+    let transport = unsafe { Fplist_get(plist, transport_key()) };
+    !transport.is_nil()
+}
+
+// Plist key under which 'async_frame_handler' stashes any bytes left over
+// after draining whatever complete frames a given filter call's chunk
+// contained, so a frame split across multiple filter invocations (a real
+// pipe delivers 'write_frame''s length prefix and payload however the OS
+// happens to chunk them) is reassembled instead of dropped.
+fn frame_buffer_key() -> LispObject {
+    intern_str("async-frame-buffer")
+}
+
 impl EmacsPipe {
     pub unsafe fn with_process(process: LispObject) -> EmacsPipe {
         let raw_proc: LispProcessRef = process.into();
@@ -195,20 +427,40 @@ impl EmacsPipe {
         input: PipeDataOption,
         output: PipeDataOption,
     ) -> (EmacsPipe, LispObject) {
-        EmacsPipe::create(handler, input, output)
+        EmacsPipe::create(handler, input, output, Wire::Pointer)
+    }
+
+    // Like 'with_handler', but for a process whose other end is a forked
+    // child rather than a thread in our own address space (see
+    // 'rust_worker_process'). The process is wired to the length-prefixed
+    // framed protocol end to end: 'async-frame-handler' as its filter
+    // instead of 'async-handler', and a plist marker so 'async_send_message'
+    // writes frames instead of boxed pointers.
+    pub fn with_handler_for_process(
+        handler: LispObject,
+        input: PipeDataOption,
+        output: PipeDataOption,
+    ) -> (EmacsPipe, LispObject) {
+        EmacsPipe::create(handler, input, output, Wire::Frame)
     }
 
     fn create(
         handler: LispObject,
         input: PipeDataOption,
         output: PipeDataOption,
+        wire: Wire,
     ) -> (EmacsPipe, LispObject) {
+        let filter_name = match wire {
+            Wire::Pointer => "async-handler",
+            Wire::Frame => "async-frame-handler",
+        };
+
         let proc = unsafe {
             // We panic here only because it will be a fairly exceptional
             // situation in which I cannot alloc these small strings on the heap
             let cstr =
                 CString::new("async-msg-buffer").expect("Failed to create pipe for async function");
-            let async_str = CString::new("async-handler")
+            let async_str = CString::new(filter_name)
                 .expect("Failed to crate string for intern function call");
             let mut proc_args = vec![
                 QCname,
@@ -232,6 +484,9 @@ impl EmacsPipe {
         plist = unsafe { Fplist_put(plist, Qcall, handler) };
         plist = unsafe { Fplist_put(plist, QCtype, input_type) };
         plist = unsafe { Fplist_put(plist, Qreturn, output_type) };
+        if wire == Wire::Frame {
+            plist = unsafe { Fplist_put(plist, transport_key(), transport_frame_marker()) };
+        }
         unsafe { Fset_process_plist(proc, plist) };
         // This should be safe due to the fact that we have created the process
         // ourselves
@@ -251,14 +506,14 @@ impl EmacsPipe {
         let mut f = unsafe { File::from_raw_fd(self.out_fd) };
         let ptr = Box::into_raw(Box::new(content));
         let bin = ptr as *mut _ as usize;
-        let result = f.write(bin.to_string().as_bytes()).map(|_| ());
+        let result = write_all_retry(&mut f, bin.to_string().as_bytes());
         f.into_raw_fd();
         result
     }
 
     fn internal_write(&mut self, bytes: &[u8]) -> std::io::Result<()> {
         let mut f = unsafe { File::from_raw_fd(self.out_subp) };
-        let result = f.write(bytes).map(|_| ());
+        let result = write_all_retry(&mut f, bytes);
         f.into_raw_fd();
         result
     }
@@ -277,9 +532,10 @@ impl EmacsPipe {
     pub fn read_next_ptr(&self) -> std::io::Result<usize> {
         let mut f = unsafe { File::from_raw_fd(self.in_fd) };
         let mut buffer = [0; ptr_size()];
-        f.read(&mut buffer)?;
-        let raw_value = usize::from_be_bytes(buffer);
+        let result = read_exact_retry(&mut f, &mut buffer);
         f.into_raw_fd();
+        result?;
+        let raw_value = usize::from_be_bytes(buffer);
 
         if raw_value == nullptr() {
             Err(std::io::Error::new(
@@ -301,6 +557,37 @@ impl EmacsPipe {
     pub fn close_stream(&mut self) -> std::io::Result<()> {
         self.internal_write(&nullptr().to_be_bytes())
     }
+
+    // Forked-worker counterparts of 'read_pend_message'/'message_lisp':
+    // the payload crosses the stream as a length-prefixed byte frame
+    // instead of a pointer, since 'rust_worker_process' runs on the other
+    // side of a 'fork()' where our pointers mean nothing.
+    fn read_pend_frame<T: SerializablePipeData>(&self) -> std::io::Result<T> {
+        let mut f = unsafe { File::from_raw_fd(self.in_fd) };
+        let result = read_frame(&mut f);
+        f.into_raw_fd();
+        result.map(T::from_stream_bytes)
+    }
+
+    fn write_frame<T: SerializablePipeData>(&mut self, content: T) -> std::io::Result<()> {
+        let mut f = unsafe { File::from_raw_fd(self.out_fd) };
+        let result = write_frame(&mut f, &content.to_stream_bytes());
+        f.into_raw_fd();
+        result
+    }
+
+    // Lisp -> worker counterpart of 'write_frame': targets 'out_subp'
+    // (mirroring 'internal_write'), the end of the pipe a forked worker's
+    // 'read_pend_frame' reads from. 'write_frame' itself targets 'out_fd',
+    // which is reserved for the worker's own replies flowing back to lisp
+    // and read by 'async_frame_handler' — using it here would inject
+    // lisp-sent bytes into that same stream and corrupt it.
+    fn write_frame_to_worker<T: SerializablePipeData>(&mut self, content: T) -> std::io::Result<()> {
+        let mut f = unsafe { File::from_raw_fd(self.out_subp) };
+        let result = write_frame(&mut f, &content.to_stream_bytes());
+        f.into_raw_fd();
+        result
+    }
 }
 
 fn eprint_if_unexpected_error(err: std::io::Error) {
@@ -311,6 +598,52 @@ fn eprint_if_unexpected_error(err: std::io::Error) {
     }
 }
 
+// Turns the errno behind an 'std::io::Error' (when there is one) into the
+// same human-readable string 'strerror(3)' would give a C caller.
+fn describe_os_error(err: &std::io::Error) -> String {
+    match err.raw_os_error() {
+        Some(errno) => {
+            let mut buf = [0 as libc::c_char; 256];
+            let message = unsafe {
+                if libc::strerror_r(errno, buf.as_mut_ptr(), buf.len()) == 0 {
+                    std::ffi::CStr::from_ptr(buf.as_ptr())
+                        .to_string_lossy()
+                        .into_owned()
+                } else {
+                    err.to_string()
+                }
+            };
+            format!("{} (errno {})", message, errno)
+        }
+        None => err.to_string(),
+    }
+}
+
+// Like 'eprint_if_unexpected_error', but also gives the lisp side a real
+// error-handling path: if an ':error' callback was registered on 'proc'
+// (see 'async_set_error_handler'), funcall it with a description of the
+// failure before the worker loop exits, instead of only writing a line
+// to stderr.
+fn report_worker_error(proc: LispObject, err: std::io::Error) {
+    if err.kind() == std::io::ErrorKind::ConnectionAborted {
+        return;
+    }
+
+    let plist = unsafe { Fprocess_plist(proc) };
+    let error_handler = unsafe { Fplist_get(plist, Qerror) };
+    if error_handler.is_nil() {
+        eprint_if_unexpected_error(err);
+        return;
+    }
+
+    let message = describe_os_error(&err);
+    let lisp_message = CString::new(message)
+        .map(|cstr| unsafe { build_string(cstr.as_ptr()) })
+        .unwrap_or(Qnil);
+    let mut buffer = vec![error_handler, proc, lisp_message];
+    unsafe { Ffuncall(3, buffer.as_mut_ptr()) };
+}
+
 pub fn rust_worker<
     INPUT: Send + PipeData,
     OUTPUT: Send + PipeData,
@@ -325,12 +658,12 @@ pub fn rust_worker<
             Ok(message) => {
                 let result = fnc(message);
                 if let Err(err) = pipe.message_lisp(result) {
-                    eprint_if_unexpected_error(err);
+                    report_worker_error(proc, err);
                     break;
                 }
             }
             Err(err) => {
-                eprint_if_unexpected_error(err);
+                report_worker_error(proc, err);
                 break;
             }
         }
@@ -339,20 +672,183 @@ pub fn rust_worker<
     proc
 }
 
+// Attaches (or replaces) the ':error' callback for 'proc', called with a
+// human-readable description if its worker dies instead of exiting
+// cleanly. See 'report_worker_error'.
+#[lisp_fn]
+pub fn async_set_error_handler(proc: LispObject, handler: LispObject) -> bool {
+    let mut plist = unsafe { Fprocess_plist(proc) };
+    plist = unsafe { Fplist_put(plist, Qerror, handler) };
+    unsafe { Fset_process_plist(proc, plist) };
+    true
+}
+
+// Like 'rust_worker', but runs the worker body in a forked child process
+// rather than a thread, isolating CPU-bound or crash-prone work from the
+// Emacs process itself. 'INPUT'/'OUTPUT' must be 'SerializablePipeData'
+// so the payload can be moved across the fork as a length-prefixed byte
+// frame rather than as a pointer, which would be meaningless once parent
+// and child address spaces diverge. The child is reaped in a dedicated
+// thread so it never lingers as a zombie.
+pub fn rust_worker_process<
+    INPUT: Send + SerializablePipeData,
+    OUTPUT: Send + SerializablePipeData,
+    T: 'static + Fn(INPUT) -> OUTPUT + Send,
+>(
+    handler: LispObject,
+    fnc: T,
+) -> LispObject {
+    let (mut pipe, proc) =
+        EmacsPipe::with_handler_for_process(handler, INPUT::marker(), OUTPUT::marker());
+
+    match unsafe { libc::fork() } {
+        -1 => report_worker_error(proc, std::io::Error::last_os_error()),
+
+        0 => {
+            // Child: our own copy of the address space, talking to the
+            // parent purely through serialized frames on the pipe from
+            // here on.
+            loop {
+                match pipe.read_pend_frame() {
+                    Ok(message) => {
+                        let result = fnc(message);
+                        if pipe.write_frame(result).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        // The child can't safely call back into the
+                        // parent's Lisp interpreter across a fork (unlike
+                        // 'report_worker_error''s 'Ffuncall'), so the best
+                        // it can do is log locally before exiting.
+                        eprint_if_unexpected_error(err);
+                        break;
+                    }
+                }
+            }
+            unsafe { libc::_exit(0) };
+        }
+
+        child_pid => {
+            thread::spawn(move || {
+                let mut status: libc::c_int = 0;
+                unsafe { libc::waitpid(child_pid, &mut status, 0) };
+                // Surface a crashed/non-zero-exit child the same way any
+                // other worker failure is surfaced, so it isn't
+                // indistinguishable from a clean exit to the lisp side.
+                if libc::WIFEXITED(status) {
+                    let code = libc::WEXITSTATUS(status);
+                    if code != 0 {
+                        report_worker_error(
+                            proc,
+                            std::io::Error::new(
+                                std::io::ErrorKind::Other,
+                                format!("worker process exited with status {}", code),
+                            ),
+                        );
+                    }
+                } else if libc::WIFSIGNALED(status) {
+                    let signal = libc::WTERMSIG(status);
+                    report_worker_error(
+                        proc,
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            format!("worker process killed by signal {}", signal),
+                        ),
+                    );
+                }
+            });
+        }
+    }
+
+    proc
+}
+
+// Handle given to a channel worker so it can push any number of
+// 'message_lisp' outputs (progress updates, streamed results, ...) for a
+// single input, rather than being limited to exactly one reply per
+// message the way 'rust_worker' is.
+pub struct ChannelSender<OUTPUT: PipeData> {
+    pipe: EmacsPipe,
+    _output: PhantomData<OUTPUT>,
+}
+
+impl<OUTPUT: PipeData> ChannelSender<OUTPUT> {
+    pub fn send(&mut self, content: OUTPUT) -> std::io::Result<()> {
+        self.pipe.message_lisp(content)
+    }
+}
+
+// A persistent, bidirectional channel layered over 'EmacsPipe'. Like
+// 'rust_worker', 'Channel' keeps the worker thread's loop alive across many
+// 'async_send_message' calls from the lisp side; unlike 'rust_worker', which
+// sends exactly one reply per message, it hands the worker closure a
+// 'ChannelSender' it can use to stream results back whenever it likes.
+pub struct Channel<INPUT, OUTPUT> {
+    _input: PhantomData<INPUT>,
+    _output: PhantomData<OUTPUT>,
+}
+
+impl<INPUT: Send + PipeData, OUTPUT: Send + PipeData> Channel<INPUT, OUTPUT> {
+    pub fn spawn<T: 'static + Fn(INPUT, &mut ChannelSender<OUTPUT>) + Send>(
+        handler: LispObject,
+        fnc: T,
+    ) -> LispObject {
+        let (reader, proc) = EmacsPipe::with_handler(handler, INPUT::marker(), OUTPUT::marker());
+        let mut sender = ChannelSender {
+            pipe: reader.clone(),
+            _output: PhantomData,
+        };
+
+        thread::spawn(move || loop {
+            match reader.read_pend_message() {
+                Ok(message) => fnc(message, &mut sender),
+                Err(err) => {
+                    report_worker_error(proc, err);
+                    break;
+                }
+            }
+        });
+
+        proc
+    }
+}
+
 fn make_return_value(ptrval: usize, option: PipeDataOption) -> LispObject {
     match option {
         PipeDataOption::STRING => {
             let content = unsafe { *Box::from_raw(ptrval as *mut String) };
             let nbytes = content.len();
-            let c_content = CString::new(content).unwrap();
-            // These unwraps should be 'safe', as we want to panic if we overflow
-            unsafe { make_string_from_utf8(c_content.as_ptr(), nbytes.try_into().unwrap()) }
+            // Build the lisp string directly from the byte slice + length
+            // instead of going through 'CString', which panics the moment
+            // 'content' contains an embedded NUL.
+            unsafe {
+                make_string_from_utf8(
+                    content.as_ptr() as *const libc::c_char,
+                    nbytes.try_into().unwrap(),
+                )
+            }
         }
 
         PipeDataOption::USER_DATA => {
             let content = unsafe { *Box::from_raw(ptrval as *mut UserData) };
             unsafe { make_user_ptr(content.finalizer, content.data) }
         }
+
+        PipeDataOption::BYTES => {
+            let content = unsafe { *Box::from_raw(ptrval as *mut Bytes) };
+            // 'content' may not be valid UTF-8; WTF-8 escape it so it
+            // survives as a valid lisp string instead of getting mangled
+            // or rejected outright.
+            let encoded = wtf8_encode(&content.0);
+            let nbytes = encoded.len();
+            unsafe {
+                make_string_from_utf8(
+                    encoded.as_ptr() as *const libc::c_char,
+                    nbytes.try_into().unwrap(),
+                )
+            }
+        }
     }
 }
 
@@ -389,6 +885,101 @@ pub fn async_handler(proc: LispObject, data: LispStringRef) -> bool {
     true
 }
 
+// 'async_handler' counterpart for a framed (fork-based) process: 'payload'
+// is already the plain bytes a 'SerializablePipeData' value serialized to,
+// not a boxed pointer, so there's no 'Box::from_raw' to do.
+fn make_return_value_from_frame(payload: Vec<u8>, option: PipeDataOption) -> LispObject {
+    match option {
+        PipeDataOption::STRING => unsafe {
+            make_string_from_utf8(
+                payload.as_ptr() as *const libc::c_char,
+                payload.len().try_into().unwrap(),
+            )
+        },
+
+        PipeDataOption::BYTES => {
+            let encoded = wtf8_encode(&payload);
+            unsafe {
+                make_string_from_utf8(
+                    encoded.as_ptr() as *const libc::c_char,
+                    encoded.len().try_into().unwrap(),
+                )
+            }
+        }
+
+        // 'rust_worker_process' can only ever be created with an 'INPUT'/
+        // 'OUTPUT' that implements 'SerializablePipeData', and 'UserData'
+        // does not (see its comment above). A framed process can't
+        // actually end up with this option unless its plist was tampered
+        // with.
+        PipeDataOption::USER_DATA => wrong_type!(Qdata, LispObject::from_data_option(option)),
+    }
+}
+
+/// Filter for a 'rust_worker_process' pipe process. Unlike 'async_handler',
+/// the bytes arriving here are a length-prefixed frame (see 'write_frame'),
+/// not the decimal string form of a pointer, because the other end is a
+/// forked child with no pointers in common with us. A single filter call
+/// may see less than a whole frame, more than one whole frame, or a frame
+/// plus the start of the next one, depending on how the pipe happened to
+/// chunk the bytes, so leftover bytes are carried across calls via
+/// 'frame_buffer_key' instead of being read as if a full frame always
+/// arrives at once.
+#[lisp_fn]
+pub fn async_frame_handler(proc: LispObject, data: LispStringRef) -> bool {
+    let mut plist = unsafe { Fprocess_plist(proc) };
+    let orig_handler = unsafe { Fplist_get(plist, Qcall) };
+
+    let buffered = unsafe { Fplist_get(plist, frame_buffer_key()) };
+    let mut bytes = if buffered.is_string() {
+        let buffered_ref: LispStringRef = buffered.into();
+        buffered_ref.as_slice().to_vec()
+    } else {
+        Vec::new()
+    };
+    bytes.extend_from_slice(data.as_slice());
+
+    let qtype = unsafe { Fplist_get(plist, Qreturn) };
+    let quoted_type = match qtype.to_data_option() {
+        Some(quoted_type) => quoted_type,
+        None => {
+            // This means that someone has mishandled the
+            // process plist and removed :type. Without this,
+            // we cannot safely execute data transfer.
+            wrong_type!(Qdata, qtype);
+        }
+    };
+
+    let mut consumed = 0;
+    while bytes.len() - consumed >= 8 {
+        let mut len_buf = [0; 8];
+        len_buf.copy_from_slice(&bytes[consumed..consumed + 8]);
+        let len = usize::try_from(u64::from_be_bytes(len_buf)).unwrap();
+        if bytes.len() - consumed < 8 + len {
+            break;
+        }
+        let payload = bytes[consumed + 8..consumed + 8 + len].to_vec();
+        consumed += 8 + len;
+
+        let retval = make_return_value_from_frame(payload, quoted_type);
+        let mut buffer = vec![orig_handler, proc, retval];
+        unsafe { Ffuncall(3, buffer.as_mut_ptr()) };
+    }
+    bytes.drain(..consumed);
+
+    let remaining = if bytes.is_empty() {
+        Qnil
+    } else {
+        unsafe {
+            make_string_from_utf8(bytes.as_ptr() as *const libc::c_char, bytes.len().try_into().unwrap())
+        }
+    };
+    plist = unsafe { Fplist_put(plist, frame_buffer_key(), remaining) };
+    unsafe { Fset_process_plist(proc, plist) };
+
+    true
+}
+
 #[async_stream]
 pub async fn async_echo(s: String) -> String {
     s
@@ -430,6 +1021,53 @@ fn internal_send_message(
 
             pipe.message_rust_worker(ud).is_ok()
         }
+        PipeDataOption::BYTES => {
+            if !message.is_string() {
+                wrong_type!(Qstringp, message);
+            }
+
+            // Unlike 'STRING', take the raw unibyte bytes as-is rather
+            // than running them through 'encode_string_utf_8' +
+            // 'from_utf8_lossy', so embedded NULs and invalid UTF-8
+            // survive this side of the round trip too.
+            let lisp_string: LispStringRef = message.into();
+            let contents = lisp_string.as_slice().to_vec();
+            pipe.message_rust_worker(Bytes(contents)).is_ok()
+        }
+    }
+}
+
+// 'internal_send_message' counterpart for a framed (fork-based) process:
+// writes the payload's own bytes, length-prefixed, instead of boxing it
+// and sending a pointer the child could never dereference.
+fn internal_send_framed_message(
+    pipe: &mut EmacsPipe,
+    message: LispObject,
+    option: PipeDataOption,
+) -> bool {
+    match option {
+        PipeDataOption::STRING => {
+            if !message.is_string() {
+                wrong_type!(Qstringp, message);
+            }
+
+            let encoded_message = unsafe { encode_string_utf_8(message, Qnil, false, Qt, Qt) };
+            let encoded_string: LispStringRef = encoded_message.into();
+            let contents = String::from_utf8_lossy(encoded_string.as_slice());
+            pipe.write_frame_to_worker(contents.into_owned()).is_ok()
+        }
+        PipeDataOption::BYTES => {
+            if !message.is_string() {
+                wrong_type!(Qstringp, message);
+            }
+
+            let lisp_string: LispStringRef = message.into();
+            let contents = lisp_string.as_slice().to_vec();
+            pipe.write_frame_to_worker(Bytes(contents)).is_ok()
+        }
+        // A framed process can never legitimately be created with
+        // USER_DATA as its ':type' (see 'make_return_value_from_frame').
+        PipeDataOption::USER_DATA => wrong_type!(Qdata, message),
     }
 }
 
@@ -439,7 +1077,11 @@ pub fn async_send_message(proc: LispObject, message: LispObject) -> bool {
     let plist = unsafe { Fprocess_plist(proc) };
     let qtype = unsafe { Fplist_get(plist, QCtype) };
     if let Some(option) = qtype.to_data_option() {
-        internal_send_message(&mut pipe, message, option)
+        if is_framed_process(plist) {
+            internal_send_framed_message(&mut pipe, message, option)
+        } else {
+            internal_send_message(&mut pipe, message, option)
+        }
     } else {
         // This means that someone has mishandled the
         // process plist and removed :type. Without this,